@@ -0,0 +1,111 @@
+"""Tests for the background generation job and its temp file cleanup."""
+
+import base64
+import os
+import shutil
+import tempfile
+import threading
+import unittest
+from unittest import mock
+
+from would_you_rather_bot.generation import remove_temp_files, run_generation
+
+
+class RunGenerationTest(unittest.TestCase):
+    """Runs generation jobs against isolated temp and working directories."""
+
+    def setUp(self):
+        self.temp_dir = tempfile.mkdtemp()
+        self.work_dir = tempfile.mkdtemp()
+        self.original_cwd = os.getcwd()
+        os.chdir(self.work_dir)
+        tempfile.tempdir = self.temp_dir
+
+    def tearDown(self):
+        tempfile.tempdir = None
+        os.chdir(self.original_cwd)
+        shutil.rmtree(self.temp_dir)
+        shutil.rmtree(self.work_dir)
+
+    def _make_temp_file(self, suffix: str) -> str:
+        """Create an empty temp file the same way the app does."""
+        with tempfile.NamedTemporaryFile(suffix=suffix, delete=False) as tmp_file:
+            return tmp_file.name
+
+    def _run(self, generate, output_path, temp_paths):
+        """Run a job on a worker thread and return its result and event."""
+        result = {"success": False, "error": None, "video_base64": None}
+        complete = threading.Event()
+        worker = threading.Thread(
+            target=run_generation,
+            args=(generate, output_path, temp_paths, result, complete),
+        )
+        worker.start()
+        worker.join(timeout=5)
+        return result, complete
+
+    def assert_no_leftover_files(self):
+        self.assertEqual(os.listdir(tempfile.gettempdir()), [])
+        self.assertEqual(os.listdir(os.getcwd()), [])
+
+    def test_failing_encode_leaves_no_temp_files(self):
+        tts_audio_path = self._make_temp_file(".wav")
+        output_path = self._make_temp_file(".mp4")
+        encode_audio_path = output_path + ".audio.m4a"
+
+        def failing_generate():
+            # Leave partial output behind, as an interrupted encode would
+            with open(output_path, "wb") as f:
+                f.write(b"partial")
+            with open(encode_audio_path, "wb") as f:
+                f.write(b"partial")
+            raise RuntimeError("encoder crashed")
+
+        result, complete = self._run(
+            failing_generate, output_path, (output_path, encode_audio_path, tts_audio_path)
+        )
+
+        self.assertTrue(complete.is_set())
+        self.assertFalse(result["success"])
+        self.assertEqual(result["error"], "encoder crashed")
+        self.assert_no_leftover_files()
+
+    def test_successful_encode_returns_video_and_leaves_no_temp_files(self):
+        output_path = self._make_temp_file(".mp4")
+
+        def generate():
+            with open(output_path, "wb") as f:
+                f.write(b"video")
+
+        result, complete = self._run(generate, output_path, (output_path, None))
+
+        self.assertTrue(complete.is_set())
+        self.assertTrue(result["success"])
+        self.assertEqual(base64.b64decode(result["video_base64"]), b"video")
+        self.assert_no_leftover_files()
+
+    def test_completion_is_signalled_when_unlink_fails(self):
+        output_path = self._make_temp_file(".mp4")
+
+        def failing_generate():
+            raise RuntimeError("encoder crashed")
+
+        with mock.patch(
+            "would_you_rather_bot.generation.os.unlink",
+            side_effect=PermissionError("permission denied"),
+        ):
+            result, complete = self._run(failing_generate, output_path, (output_path,))
+
+        self.assertTrue(complete.is_set())
+        self.assertEqual(result["error"], "encoder crashed")
+
+    def test_remove_temp_files_ignores_missing_files(self):
+        missing_path = os.path.join(self.temp_dir, "missing.mp4")
+
+        remove_temp_files((missing_path, None))
+
+        self.assert_no_leftover_files()
+
+
+if __name__ == "__main__":
+    unittest.main()
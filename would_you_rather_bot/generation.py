@@ -0,0 +1,66 @@
+"""Background video generation job with temporary file cleanup.
+
+Kept free of Reflex and moviepy imports so the cleanup behaviour can be tested
+on its own.
+"""
+
+import base64
+import os
+import threading
+from typing import Callable, Iterable, Optional
+
+
+def remove_temp_files(paths: Iterable[Optional[str]]) -> None:
+    """Delete temporary files created for a generation.
+
+    Cleanup is best-effort: a file that is already gone or can't be removed
+    must not stop the worker from signalling completion or mask the real result.
+
+    Args:
+        paths: File paths to delete. None entries are skipped.
+    """
+    for path in paths:
+        if not path:
+            continue
+        try:
+            os.unlink(path)
+        except OSError:
+            pass
+
+
+def run_generation(
+    generate: Callable[[], None],
+    output_path: str,
+    temp_paths: Iterable[Optional[str]],
+    result: dict,
+    complete: threading.Event,
+) -> None:
+    """Run a video generation job, then clean up its temporary files.
+
+    Intended as a worker thread target. Once started, it owns every file in
+    temp_paths and removes them when the job finishes, whether it succeeds or
+    fails. The completion event is always set, even if cleanup fails.
+
+    Args:
+        generate: Callable that writes the finished video to output_path.
+        output_path: Path of the generated video.
+        temp_paths: Temporary files to delete afterwards, including output_path.
+        result: Dict updated with "success", "error" and "video_base64".
+        complete: Event set once the job and its cleanup have finished.
+    """
+    try:
+        try:
+            generate()
+
+            # Read the video file and encode as base64
+            with open(output_path, "rb") as f:
+                video_bytes = f.read()
+            result["video_base64"] = base64.b64encode(video_bytes).decode("utf-8")
+            result["success"] = True
+
+        except Exception as e:
+            result["error"] = str(e)
+        finally:
+            remove_temp_files(temp_paths)
+    finally:
+        complete.set()
@@ -91,6 +91,22 @@ class VideoGenerator:
         self.font_path = font_path
         self._calculate_offsets()
 
+    @staticmethod
+    def temp_audio_path_for(output_path: str) -> str:
+        """Get the path moviepy uses for its intermediate audio track.
+
+        moviepy otherwise writes this file to the working directory and only
+        removes it after a successful encode, so callers pin it next to the
+        output and clean it up themselves.
+
+        Args:
+            output_path: Path where the video will be saved.
+
+        Returns:
+            The path of the temporary audio file for this output.
+        """
+        return output_path + ".audio.m4a"
+
     def _calculate_offsets(self):
         """Calculate positioning offsets for images and text."""
         # Image vertical positions (upper and lower halves)
@@ -133,6 +149,7 @@ class VideoGenerator:
         Raises:
             VideoGeneratorError: If video generation fails.
         """
+        final_clip = None
         try:
             if progress_callback:
                 progress_callback(5, "Initializing...")
@@ -237,16 +254,12 @@ class VideoGenerator:
                 fps=self.FPS,
                 codec="libx264",
                 audio_codec="aac" if audio_path else None,
+                temp_audiofile=self.temp_audio_path_for(output_path),
                 logger=logger,
             )
 
             if progress_callback:
                 progress_callback(98, "Finalizing...")
-
-            # Clean up
-            final_clip.close()
-
-            if progress_callback:
                 progress_callback(100, "Complete!")
 
             return output_path
@@ -255,6 +268,10 @@ class VideoGenerator:
             raise
         except Exception as e:
             raise VideoGeneratorError(f"Video generation failed: {str(e)}")
+        finally:
+            # Release file readers even when encoding fails
+            if final_clip is not None:
+                final_clip.close()
 
     def _create_text_clip(
         self, text: str, y_offset: float, duration: Optional[float] = None
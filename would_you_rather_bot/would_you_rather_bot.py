@@ -1,7 +1,6 @@
 """Would You Rather Bot - Main Reflex Application."""
 
 import base64
-import os
 import random
 import tempfile
 import uuid
@@ -21,6 +20,7 @@ from .services.tts_generator import (
     AVAILABLE_VOICES,
 )
 from .components import floating_support_button
+from .generation import remove_temp_files, run_generation
 
 
 # Color constants matching the video style
@@ -302,6 +302,13 @@ class State(rx.State):
         generation_complete = threading.Event()
         generation_result = {"success": False, "error": None, "video_base64": None}
 
+        # Temporary file paths. Once the worker thread starts it owns them and
+        # removes them itself, so they are never deleted while still in use.
+        tts_audio_path = None
+        output_path = None
+        encode_audio_path = None
+        worker_started = False
+
         try:
             # Get data from state
             async with self:
@@ -324,7 +331,6 @@ class State(rx.State):
             lower_image = ImageProcessor.process_uploaded_image(lower_image_data)
 
             # Generate TTS audio if enabled
-            tts_audio_path = None
             if enable_tts:
                 async with self:
                     self.generation_progress = 3
@@ -347,42 +353,42 @@ class State(rx.State):
             # Create a temporary file for the video
             with tempfile.NamedTemporaryFile(suffix=".mp4", delete=False) as tmp_file:
                 output_path = tmp_file.name
+            encode_audio_path = VideoGenerator.temp_audio_path_for(output_path)
 
             def progress_callback(progress: int, status: str):
                 """Callback to update progress from video generator."""
                 progress_state["progress"] = progress
                 progress_state["status"] = status
 
-            def generate_in_thread():
-                """Run video generation in a separate thread."""
-                try:
-                    generator = VideoGenerator()
-                    generator.generate(
-                        upper_text=upper_text,
-                        lower_text=lower_text,
-                        upper_image=upper_image,
-                        lower_image=lower_image,
-                        output_path=output_path,
-                        progress_callback=progress_callback,
-                        upper_percentage=upper_percentage,
-                        lower_percentage=lower_percentage,
-                        audio_path=tts_audio_path,
-                    )
-
-                    # Read the video file and encode as base64
-                    with open(output_path, "rb") as f:
-                        video_bytes = f.read()
-                    generation_result["video_base64"] = base64.b64encode(video_bytes).decode("utf-8")
-                    generation_result["success"] = True
-
-                except Exception as e:
-                    generation_result["error"] = str(e)
-                finally:
-                    generation_complete.set()
+            def encode_video():
+                """Render the video to output_path."""
+                generator = VideoGenerator()
+                generator.generate(
+                    upper_text=upper_text,
+                    lower_text=lower_text,
+                    upper_image=upper_image,
+                    lower_image=lower_image,
+                    output_path=output_path,
+                    progress_callback=progress_callback,
+                    upper_percentage=upper_percentage,
+                    lower_percentage=lower_percentage,
+                    audio_path=tts_audio_path,
+                )
 
             # Start video generation in a separate thread
-            gen_thread = threading.Thread(target=generate_in_thread, daemon=True)
+            gen_thread = threading.Thread(
+                target=run_generation,
+                args=(
+                    encode_video,
+                    output_path,
+                    (output_path, encode_audio_path, tts_audio_path),
+                    generation_result,
+                    generation_complete,
+                ),
+                daemon=True,
+            )
             gen_thread.start()
+            worker_started = True
 
             # Poll for progress updates while generation is running
             last_progress = -1
@@ -398,12 +404,6 @@ class State(rx.State):
 
                 await asyncio.sleep(0.1)  # Poll every 100ms
 
-            # Clean up temporary files
-            if os.path.exists(output_path):
-                os.unlink(output_path)
-            if tts_audio_path and os.path.exists(tts_audio_path):
-                os.unlink(tts_audio_path)
-
             # Handle result
             if generation_result["success"]:
                 async with self:
@@ -432,6 +432,10 @@ class State(rx.State):
                 self.is_generating = False
                 self.generation_progress = 0
                 self.generation_status = ""
+        finally:
+            # Clean up temporary files unless the worker thread is responsible
+            if not worker_started:
+                remove_temp_files((output_path, encode_audio_path, tts_audio_path))
 
     @rx.event
     def download_video(self):